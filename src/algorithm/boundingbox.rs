@@ -1,6 +1,7 @@
 use num::{Float};
 
-use types::{Bbox, LineString};
+use types::{Bbox, Point, LineString, Polygon, MultiPoint, MultiLineString, MultiPolygon,
+            Geometry, GeometryCollection};
 
 /// Calculation of the bounding box of a geometry.
 
@@ -25,34 +26,64 @@ pub trait BoundingBox<T: Float> {
     fn bbox(&self) -> Option<Bbox<T>>;
 }
 
-fn get_bbox<T>(line: &LineString<T>) -> Option<Bbox<T>>
-    where T: Float
+/// Fold a sequence of coordinates into the smallest `Bbox` enclosing them all,
+/// or `None` if the sequence is empty.
+fn bbox_of_coords<T, I>(coords: I) -> Option<Bbox<T>>
+    where T: Float, I: Iterator<Item = (T, T)>
+{
+    let mut bbox: Option<Bbox<T>> = None;
+    for (x, y) in coords {
+        bbox = Some(match bbox {
+            None => Bbox{xmin: x, xmax: x, ymin: y, ymax: y},
+            Some(b) => Bbox{
+                xmin: if x < b.xmin { x } else { b.xmin },
+                xmax: if x > b.xmax { x } else { b.xmax },
+                ymin: if y < b.ymin { y } else { b.ymin },
+                ymax: if y > b.ymax { y } else { b.ymax },
+            },
+        });
+    }
+    bbox
+}
+
+/// Union a sequence of (possibly absent) bounding boxes into the smallest
+/// `Bbox` enclosing them all, or `None` if none were present.
+fn union_bboxes<T, I>(boxes: I) -> Option<Bbox<T>>
+    where T: Float, I: Iterator<Item = Option<Bbox<T>>>
 {
-    let vect = &line.0;
-    if vect.is_empty() {
-        return None;
-    }
-    if vect.len() == 1 {
-        return Some(Bbox{xmin: vect[0].x(), ymax: vect[0].y(),
-                         xmax: vect[0].x(), ymin: vect[0].y()})
-    } else {
-        let (mut xmax, mut xmin) = (T::neg_infinity(), T::infinity());
-        let (mut ymax, mut ymin) = (T::neg_infinity(), T::infinity());
-        for pnt in vect.iter() {
-            let (px, py) = (pnt.x(), pnt.y());
-            if px > xmax {
-                xmax = px;
-            } else if px < xmin {
-                xmin = px;
-            }
-            if py > ymax {
-                ymax = py;
-            } else if py < ymin {
-                ymin = py;
-            }
+    boxes.fold(None, |acc, b| {
+        match (acc, b) {
+            (None, b) => b,
+            (a, None) => a,
+            (Some(a), Some(b)) => Some(Bbox{
+                xmin: if a.xmin < b.xmin { a.xmin } else { b.xmin },
+                xmax: if a.xmax > b.xmax { a.xmax } else { b.xmax },
+                ymin: if a.ymin < b.ymin { a.ymin } else { b.ymin },
+                ymax: if a.ymax > b.ymax { a.ymax } else { b.ymax },
+            }),
         }
-        Some(Bbox{xmin: xmin, ymax: ymax,
-                  xmax: xmax, ymin: ymin})
+    })
+}
+
+impl<T> BoundingBox<T> for Point<T>
+    where T: Float
+{
+    ///
+    /// Return the BoundingBox for a Point (degenerate: a box of zero area).
+    ///
+    fn bbox(&self) -> Option<Bbox<T>> {
+        Some(Bbox{xmin: self.x(), xmax: self.x(), ymin: self.y(), ymax: self.y()})
+    }
+}
+
+impl<T> BoundingBox<T> for MultiPoint<T>
+    where T: Float
+{
+    ///
+    /// Return the BoundingBox for a MultiPoint
+    ///
+    fn bbox(&self) -> Option<Bbox<T>> {
+        bbox_of_coords(self.0.iter().map(|p| (p.x(), p.y())))
     }
 }
 
@@ -63,14 +94,85 @@ impl<T> BoundingBox<T> for LineString<T>
     /// Return the BoundingBox for a LineString
     ///
     fn bbox(&self) -> Option<Bbox<T>> {
-        get_bbox(&self)
+        bbox_of_coords(self.0.iter().map(|p| (p.x(), p.y())))
+    }
+}
+
+impl<T> BoundingBox<T> for MultiLineString<T>
+    where T: Float
+{
+    ///
+    /// Return the BoundingBox for a MultiLineString: the union of its
+    /// members' boxes.
+    ///
+    fn bbox(&self) -> Option<Bbox<T>> {
+        union_bboxes(self.0.iter().map(|ls| ls.bbox()))
+    }
+}
+
+impl<T> BoundingBox<T> for Polygon<T>
+    where T: Float
+{
+    ///
+    /// Return the BoundingBox for a Polygon: the union of its exterior and
+    /// interior ring boxes.
+    ///
+    fn bbox(&self) -> Option<Bbox<T>> {
+        union_bboxes(Some(self.exterior.bbox())
+                         .into_iter()
+                         .chain(self.interiors.iter().map(|ls| ls.bbox())))
+    }
+}
+
+impl<T> BoundingBox<T> for MultiPolygon<T>
+    where T: Float
+{
+    ///
+    /// Return the BoundingBox for a MultiPolygon: the union of its members'
+    /// boxes.
+    ///
+    fn bbox(&self) -> Option<Bbox<T>> {
+        union_bboxes(self.0.iter().map(|p| p.bbox()))
+    }
+}
+
+impl<T> BoundingBox<T> for GeometryCollection<T>
+    where T: Float
+{
+    ///
+    /// Return the BoundingBox for a GeometryCollection: the union of its
+    /// members' boxes.
+    ///
+    fn bbox(&self) -> Option<Bbox<T>> {
+        union_bboxes(self.0.iter().map(|g| g.bbox()))
+    }
+}
+
+impl<T> BoundingBox<T> for Geometry<T>
+    where T: Float
+{
+    ///
+    /// Return the BoundingBox for a Geometry, dispatching to the concrete
+    /// variant it wraps.
+    ///
+    fn bbox(&self) -> Option<Bbox<T>> {
+        match *self {
+            Geometry::Point(ref g) => g.bbox(),
+            Geometry::LineString(ref g) => g.bbox(),
+            Geometry::Polygon(ref g) => g.bbox(),
+            Geometry::MultiPoint(ref g) => g.bbox(),
+            Geometry::MultiLineString(ref g) => g.bbox(),
+            Geometry::MultiPolygon(ref g) => g.bbox(),
+            Geometry::GeometryCollection(ref g) => g.bbox(),
+        }
     }
 }
 
 
 #[cfg(test)]
 mod test {
-    use types::{Point, LineString, Bbox};
+    use types::{Point, LineString, Polygon, MultiPoint, MultiPolygon, GeometryCollection,
+                Geometry, Bbox};
     use algorithm::boundingbox::BoundingBox;
 
     #[test]
@@ -98,4 +200,59 @@ mod test {
         let bbox : Bbox<f64> = Bbox{xmin: -4., ymax: 4., xmax: 2., ymin: -3.};
         assert_eq!(bbox, linestring.bbox().unwrap());
     }
+    #[test]
+    fn point_bbox_test() {
+        let p = Point::new(1.5f64, -2.5);
+        let bbox = Bbox{xmin: 1.5, xmax: 1.5, ymin: -2.5, ymax: -2.5};
+        assert_eq!(bbox, p.bbox().unwrap());
+    }
+    #[test]
+    fn empty_multipoint_test() {
+        let multipoint : MultiPoint<f64> = MultiPoint(Vec::new());
+        assert!(multipoint.bbox().is_none());
+    }
+    #[test]
+    fn multipoint_test() {
+        let multipoint = MultiPoint(vec![Point::new(1., 1.), Point::new(-2., 3.)]);
+        let bbox = Bbox{xmin: -2., xmax: 1., ymin: 1., ymax: 3.};
+        assert_eq!(bbox, multipoint.bbox().unwrap());
+    }
+    #[test]
+    fn polygon_with_hole_test() {
+        let exterior = LineString(vec![Point::new(0., 0.), Point::new(0., 10.),
+                                        Point::new(10., 10.), Point::new(10., 0.),
+                                        Point::new(0., 0.)]);
+        let interior = LineString(vec![Point::new(2., 2.), Point::new(2., 4.),
+                                        Point::new(4., 4.), Point::new(4., 2.),
+                                        Point::new(2., 2.)]);
+        let polygon = Polygon::new(exterior, vec![interior]);
+        let bbox = Bbox{xmin: 0., xmax: 10., ymin: 0., ymax: 10.};
+        assert_eq!(bbox, polygon.bbox().unwrap());
+    }
+    #[test]
+    fn multipolygon_test() {
+        let p1 = Polygon::new(LineString(vec![Point::new(0., 0.), Point::new(0., 1.),
+                                               Point::new(1., 1.), Point::new(1., 0.),
+                                               Point::new(0., 0.)]),
+                               Vec::new());
+        let p2 = Polygon::new(LineString(vec![Point::new(10., 10.), Point::new(10., 11.),
+                                               Point::new(11., 11.), Point::new(11., 10.),
+                                               Point::new(10., 10.)]),
+                               Vec::new());
+        let multipolygon = MultiPolygon(vec![p1, p2]);
+        let bbox = Bbox{xmin: 0., xmax: 11., ymin: 0., ymax: 11.};
+        assert_eq!(bbox, multipolygon.bbox().unwrap());
+    }
+    #[test]
+    fn empty_geometrycollection_test() {
+        let gc : GeometryCollection<f64> = GeometryCollection(Vec::new());
+        assert!(gc.bbox().is_none());
+    }
+    #[test]
+    fn geometrycollection_test() {
+        let gc = GeometryCollection(vec![Geometry::Point(Point::new(0., 0.)),
+                                          Geometry::Point(Point::new(5., 5.))]);
+        let bbox = Bbox{xmin: 0., xmax: 5., ymin: 0., ymax: 5.};
+        assert_eq!(bbox, gc.bbox().unwrap());
+    }
 }
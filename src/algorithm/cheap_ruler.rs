@@ -0,0 +1,142 @@
+use num::Float;
+
+use types::{Point, LineString};
+
+/// The unit of distance a `CheapRuler` measures in.
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub enum Unit {
+    Meters,
+    Kilometers,
+    Miles,
+}
+
+impl Unit {
+    /// The number of this unit in one meter.
+    fn per_meter<T: Float>(&self) -> T {
+        match *self {
+            Unit::Meters => T::from(1.0).unwrap(),
+            Unit::Kilometers => T::from(0.001).unwrap(),
+            Unit::Miles => T::from(0.000621371).unwrap(),
+        }
+    }
+}
+
+/// A fast, approximate ruler for distance and length calculations on a city
+/// scale, valid for points `[x = longitude, y = latitude]` in degrees that
+/// stay close to the reference latitude it was built with.
+///
+/// This trades the precision of a full geodesic (Haversine/Vincenty)
+/// calculation for speed, by linearizing the meters-per-degree scale factors
+/// around a single reference latitude. The approximation degrades as points
+/// move away from that latitude or span a large region.
+pub struct CheapRuler<T: Float> {
+    kx: T,
+    ky: T,
+}
+
+impl<T: Float> CheapRuler<T> {
+    /// Build a ruler valid near `latitude` degrees, measuring in `unit`.
+    pub fn new(latitude: T, unit: Unit) -> CheapRuler<T> {
+        let mul = unit.per_meter();
+        let cos = latitude.to_radians().cos();
+        let cos2 = cos * cos * T::from(2.0).unwrap() - T::one();
+        let cos3 = cos * cos2 * T::from(2.0).unwrap() - cos;
+        let cos4 = cos * cos3 * T::from(2.0).unwrap() - cos2;
+        let cos5 = cos * cos4 * T::from(2.0).unwrap() - cos3;
+
+        let kx = (T::from(111.41513).unwrap() * cos
+                  - T::from(0.09455).unwrap() * cos3
+                  + T::from(0.00012).unwrap() * cos5) * T::from(1000.0).unwrap() * mul;
+        let ky = (T::from(111.13209).unwrap()
+                  - T::from(0.56605).unwrap() * cos2
+                  + T::from(0.0012).unwrap() * cos4) * T::from(1000.0).unwrap() * mul;
+
+        CheapRuler { kx: kx, ky: ky }
+    }
+
+    /// Derive a reference latitude from slippy-map tile coordinates, as
+    /// described by the Slippy Map Tilenames spec.
+    pub fn from_tile(y: u32, z: u32, unit: Unit) -> CheapRuler<T> {
+        let y_center = T::from(y).unwrap() + T::from(0.5).unwrap();
+        let n = T::from(::std::f64::consts::PI).unwrap() *
+                (T::one() - T::from(2.0).unwrap() * y_center /
+                            T::from(2u32.pow(z)).unwrap());
+        let latitude = n.sinh().atan().to_degrees();
+        CheapRuler::new(latitude, unit)
+    }
+
+    /// The squared distance between `a` and `b`, avoiding the `sqrt` in
+    /// `distance` when only relative ordering/thresholding is needed.
+    pub fn square_distance(&self, a: &Point<T>, b: &Point<T>) -> T {
+        let dx = (a.x() - b.x()) * self.kx;
+        let dy = (a.y() - b.y()) * self.ky;
+        dx * dx + dy * dy
+    }
+
+    /// The distance between `a` and `b`, in the ruler's unit.
+    pub fn distance(&self, a: &Point<T>, b: &Point<T>) -> T {
+        self.square_distance(a, b).sqrt()
+    }
+
+    /// The length of `line`, summed over its consecutive segments.
+    pub fn line_distance(&self, line: &LineString<T>) -> T {
+        line.0.windows(2).fold(T::zero(), |total, pair| {
+            total + self.distance(&pair[0], &pair[1])
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use types::{Point, LineString};
+    use algorithm::cheap_ruler::{CheapRuler, Unit};
+
+    #[test]
+    fn distance_matches_square_distance_test() {
+        let ruler = CheapRuler::new(40.0f64, Unit::Meters);
+        let a = Point::new(-73.99, 40.74);
+        let b = Point::new(-73.98, 40.75);
+        let d = ruler.distance(&a, &b);
+        assert!((d * d - ruler.square_distance(&a, &b)).abs() < 1e-6);
+    }
+
+    #[test]
+    fn zero_distance_test() {
+        let ruler = CheapRuler::new(40.0f64, Unit::Meters);
+        let p = Point::new(-73.99, 40.74);
+        assert_eq!(0.0, ruler.distance(&p, &p));
+    }
+
+    #[test]
+    fn unit_scaling_test() {
+        let meters = CheapRuler::new(40.0f64, Unit::Meters);
+        let kilometers = CheapRuler::new(40.0f64, Unit::Kilometers);
+        let a = Point::new(-73.99, 40.74);
+        let b = Point::new(-73.98, 40.75);
+        let d_m = meters.distance(&a, &b);
+        let d_km = kilometers.distance(&a, &b);
+        assert!((d_m / 1000.0 - d_km).abs() < 1e-6);
+    }
+
+    #[test]
+    fn from_tile_matches_tile_center_latitude_test() {
+        // The single z=0 tile spans the whole world; its center row, at
+        // y=0.5, sits on the equator.
+        let ruler = CheapRuler::from_tile(0, 0, Unit::Meters);
+        let expected = CheapRuler::new(0.0f64, Unit::Meters);
+        let a = Point::new(0f64, 0.);
+        let b = Point::new(1., 1.);
+        assert!((ruler.distance(&a, &b) - expected.distance(&a, &b)).abs() < 1e-6);
+    }
+
+    #[test]
+    fn line_distance_test() {
+        let ruler = CheapRuler::new(40.0f64, Unit::Meters);
+        let line = LineString(vec![Point::new(-73.99, 40.74),
+                                    Point::new(-73.98, 40.75),
+                                    Point::new(-73.97, 40.76)]);
+        let expected = ruler.distance(&line.0[0], &line.0[1]) +
+                       ruler.distance(&line.0[1], &line.0[2]);
+        assert!((expected - ruler.line_distance(&line)).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,289 @@
+use num::Float;
+
+use types::{Bbox, Point, LineString, Polygon};
+use algorithm::boundingbox::BoundingBox;
+
+const INSIDE: u8 = 0;
+const LEFT: u8 = 1;
+const RIGHT: u8 = 2;
+const BOTTOM: u8 = 4;
+const TOP: u8 = 8;
+
+fn region_code<T: Float>(p: &Point<T>, window: &Bbox<T>) -> u8 {
+    let mut code = INSIDE;
+    if p.x() < window.xmin {
+        code |= LEFT;
+    } else if p.x() > window.xmax {
+        code |= RIGHT;
+    }
+    if p.y() < window.ymin {
+        code |= BOTTOM;
+    } else if p.y() > window.ymax {
+        code |= TOP;
+    }
+    code
+}
+
+/// Cohen-Sutherland clipping of a single segment against `window`, or
+/// `None` if no part of it lies inside.
+fn clip_segment<T: Float>(a: &Point<T>, b: &Point<T>, window: &Bbox<T>) -> Option<(Point<T>, Point<T>)> {
+    let (mut x0, mut y0) = (a.x(), a.y());
+    let (mut x1, mut y1) = (b.x(), b.y());
+    let mut code0 = region_code(&Point::new(x0, y0), window);
+    let mut code1 = region_code(&Point::new(x1, y1), window);
+
+    loop {
+        if code0 | code1 == INSIDE {
+            return Some((Point::new(x0, y0), Point::new(x1, y1)));
+        } else if code0 & code1 != 0 {
+            return None;
+        }
+
+        let code_out = if code0 != INSIDE { code0 } else { code1 };
+        let (x, y);
+        if code_out & TOP != 0 {
+            x = x0 + (x1 - x0) * (window.ymax - y0) / (y1 - y0);
+            y = window.ymax;
+        } else if code_out & BOTTOM != 0 {
+            x = x0 + (x1 - x0) * (window.ymin - y0) / (y1 - y0);
+            y = window.ymin;
+        } else if code_out & RIGHT != 0 {
+            y = y0 + (y1 - y0) * (window.xmax - x0) / (x1 - x0);
+            x = window.xmax;
+        } else {
+            y = y0 + (y1 - y0) * (window.xmin - x0) / (x1 - x0);
+            x = window.xmin;
+        }
+
+        if code_out == code0 {
+            x0 = x;
+            y0 = y;
+            code0 = region_code(&Point::new(x0, y0), window);
+        } else {
+            x1 = x;
+            y1 = y;
+            code1 = region_code(&Point::new(x1, y1), window);
+        }
+    }
+}
+
+/// Clip every segment of `line` against `window`, reassembling the
+/// surviving portions into one or more disjoint `LineString`s.
+fn clip_line<T: Float>(line: &LineString<T>, window: &Bbox<T>) -> Vec<LineString<T>> {
+    let mut result = Vec::new();
+    let mut current: Vec<Point<T>> = Vec::new();
+    for pair in line.0.windows(2) {
+        match clip_segment(&pair[0], &pair[1], window) {
+            Some((a, b)) => {
+                match current.last() {
+                    Some(last) if *last == a => {}
+                    _ => {
+                        if !current.is_empty() {
+                            result.push(LineString(current));
+                        }
+                        current = vec![a];
+                    }
+                }
+                current.push(b);
+            }
+            None => {
+                if !current.is_empty() {
+                    result.push(LineString(current));
+                    current = Vec::new();
+                }
+            }
+        }
+    }
+    if !current.is_empty() {
+        result.push(LineString(current));
+    }
+    result
+}
+
+/// One pass of Sutherland-Hodgman clipping against a single half-plane,
+/// described by `inside` and the function to compute the boundary
+/// intersection of an edge that crosses it.
+fn clip_half_plane<T, In, Ix>(points: &[Point<T>], inside: In, intersect: Ix) -> Vec<Point<T>>
+    where T: Float, In: Fn(&Point<T>) -> bool, Ix: Fn(&Point<T>, &Point<T>) -> Point<T>
+{
+    if points.is_empty() {
+        return Vec::new();
+    }
+    let mut output = Vec::new();
+    let mut prev = points[points.len() - 1];
+    let mut prev_inside = inside(&prev);
+    for &curr in points {
+        let curr_inside = inside(&curr);
+        if curr_inside {
+            if !prev_inside {
+                output.push(intersect(&prev, &curr));
+            }
+            output.push(curr);
+        } else if prev_inside {
+            output.push(intersect(&prev, &curr));
+        }
+        prev = curr;
+        prev_inside = curr_inside;
+    }
+    output
+}
+
+/// Sutherland-Hodgman clipping of a polygon ring against the four edges of
+/// `window`, returning an empty `LineString` if nothing survives.
+fn clip_ring<T: Float>(ring: &LineString<T>, window: &Bbox<T>) -> LineString<T> {
+    let mut points = ring.0.clone();
+    points = clip_half_plane(&points,
+                              |p| p.x() >= window.xmin,
+                              |a, b| {
+                                  let t = (window.xmin - a.x()) / (b.x() - a.x());
+                                  Point::new(window.xmin, a.y() + t * (b.y() - a.y()))
+                              });
+    points = clip_half_plane(&points,
+                              |p| p.x() <= window.xmax,
+                              |a, b| {
+                                  let t = (window.xmax - a.x()) / (b.x() - a.x());
+                                  Point::new(window.xmax, a.y() + t * (b.y() - a.y()))
+                              });
+    points = clip_half_plane(&points,
+                              |p| p.y() >= window.ymin,
+                              |a, b| {
+                                  let t = (window.ymin - a.y()) / (b.y() - a.y());
+                                  Point::new(a.x() + t * (b.x() - a.x()), window.ymin)
+                              });
+    points = clip_half_plane(&points,
+                              |p| p.y() <= window.ymax,
+                              |a, b| {
+                                  let t = (window.ymax - a.y()) / (b.y() - a.y());
+                                  Point::new(a.x() + t * (b.x() - a.x()), window.ymax)
+                              });
+    if points.len() >= 3 && *points.last().unwrap() != points[0] {
+        let first = points[0];
+        points.push(first);
+    }
+    LineString(points)
+}
+
+/// Clipping of a geometry to an axis-aligned `Bbox` window.
+pub trait Clip<T: Float> {
+    type Output;
+
+    /// Clip `self` to `window`.
+    fn clip(&self, window: &Bbox<T>) -> Self::Output;
+}
+
+impl<T> Clip<T> for LineString<T>
+    where T: Float
+{
+    type Output = Vec<LineString<T>>;
+
+    fn clip(&self, window: &Bbox<T>) -> Vec<LineString<T>> {
+        match self.bbox() {
+            None => Vec::new(),
+            Some(bbox) => {
+                if window.contains(&bbox) {
+                    vec![self.clone()]
+                } else if window.intersection(&bbox).is_none() {
+                    Vec::new()
+                } else {
+                    clip_line(self, window)
+                }
+            }
+        }
+    }
+}
+
+impl<T> Clip<T> for Polygon<T>
+    where T: Float
+{
+    type Output = Option<Polygon<T>>;
+
+    fn clip(&self, window: &Bbox<T>) -> Option<Polygon<T>> {
+        match self.bbox() {
+            None => None,
+            Some(bbox) => {
+                if window.contains(&bbox) {
+                    return Some(self.clone());
+                }
+                if window.intersection(&bbox).is_none() {
+                    return None;
+                }
+                let exterior = clip_ring(&self.exterior, window);
+                if exterior.0.len() < 3 {
+                    return None;
+                }
+                let interiors = self.interiors
+                    .iter()
+                    .map(|ring| clip_ring(ring, window))
+                    .filter(|ring| ring.0.len() >= 3)
+                    .collect();
+                Some(Polygon::new(exterior, interiors))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use types::{Point, LineString, Polygon, Bbox};
+    use algorithm::clip::Clip;
+
+    #[test]
+    fn linestring_fully_inside_test() {
+        let line = LineString(vec![Point::new(1f64, 1.), Point::new(2., 2.)]);
+        let window = Bbox{xmin: 0., xmax: 10., ymin: 0., ymax: 10.};
+        assert_eq!(vec![line.clone()], line.clip(&window));
+    }
+    #[test]
+    fn linestring_fully_outside_test() {
+        let line = LineString(vec![Point::new(20f64, 20.), Point::new(30., 30.)]);
+        let window = Bbox{xmin: 0., xmax: 10., ymin: 0., ymax: 10.};
+        assert!(line.clip(&window).is_empty());
+    }
+    #[test]
+    fn linestring_crossing_edge_test() {
+        let line = LineString(vec![Point::new(-5f64, 5.), Point::new(5., 5.)]);
+        let window = Bbox{xmin: 0., xmax: 10., ymin: 0., ymax: 10.};
+        let clipped = line.clip(&window);
+        assert_eq!(1, clipped.len());
+        assert_eq!(Point::new(0., 5.), clipped[0].0[0]);
+        assert_eq!(Point::new(5., 5.), clipped[0].0[1]);
+    }
+    #[test]
+    fn linestring_split_into_two_pieces_test() {
+        // Exits the window through the right edge, loops around outside it,
+        // then re-enters through the top edge: two disjoint surviving runs.
+        let line = LineString(vec![Point::new(5f64, 5.), Point::new(15., 5.), Point::new(15., 15.),
+                                    Point::new(5., 15.), Point::new(5., 5.)]);
+        let window = Bbox{xmin: 0., xmax: 10., ymin: 0., ymax: 10.};
+        let clipped = line.clip(&window);
+        assert_eq!(2, clipped.len());
+    }
+    #[test]
+    fn polygon_fully_inside_test() {
+        let polygon = Polygon::new(
+            LineString(vec![Point::new(1f64, 1.), Point::new(1., 2.), Point::new(2., 2.),
+                            Point::new(2., 1.), Point::new(1., 1.)]),
+            Vec::new());
+        let window = Bbox{xmin: 0., xmax: 10., ymin: 0., ymax: 10.};
+        assert_eq!(Some(polygon.clone()), polygon.clip(&window));
+    }
+    #[test]
+    fn polygon_fully_outside_test() {
+        let polygon = Polygon::new(
+            LineString(vec![Point::new(20f64, 20.), Point::new(20., 21.), Point::new(21., 21.),
+                            Point::new(21., 20.), Point::new(20., 20.)]),
+            Vec::new());
+        let window = Bbox{xmin: 0., xmax: 10., ymin: 0., ymax: 10.};
+        assert!(polygon.clip(&window).is_none());
+    }
+    #[test]
+    fn polygon_overlapping_window_test() {
+        let polygon = Polygon::new(
+            LineString(vec![Point::new(-5f64, -5.), Point::new(-5., 5.), Point::new(5., 5.),
+                            Point::new(5., -5.), Point::new(-5., -5.)]),
+            Vec::new());
+        let window = Bbox{xmin: 0., xmax: 10., ymin: 0., ymax: 10.};
+        let clipped = polygon.clip(&window).unwrap();
+        assert!(clipped.exterior.0.iter().all(|p| p.x() >= 0. && p.y() >= 0.));
+    }
+}
@@ -0,0 +1,247 @@
+use num::Float;
+
+use types::{Point, LineString, Polygon};
+
+/// Calculation of the minimum Euclidean distance between two geometries.
+pub trait Distance<T: Float, Rhs> {
+    /// Return the minimum distance between `self` and `rhs`.
+    fn distance(&self, rhs: &Rhs) -> T;
+}
+
+/// The distance from `p` to the segment `a`-`b`, via the closest point on
+/// the segment to `p`.
+fn point_segment_distance<T>(p: &Point<T>, a: &Point<T>, b: &Point<T>) -> T
+    where T: Float
+{
+    let (dx, dy) = (b.x() - a.x(), b.y() - a.y());
+    let len2 = dx * dx + dy * dy;
+    if len2 == T::zero() {
+        return point_point_distance(p, a);
+    }
+    let t = ((p.x() - a.x()) * dx + (p.y() - a.y()) * dy) / len2;
+    let t = if t < T::zero() { T::zero() } else if t > T::one() { T::one() } else { t };
+    let proj = Point::new(a.x() + t * dx, a.y() + t * dy);
+    point_point_distance(p, &proj)
+}
+
+fn point_point_distance<T>(a: &Point<T>, b: &Point<T>) -> T
+    where T: Float
+{
+    let (dx, dy) = (a.x() - b.x(), a.y() - b.y());
+    (dx * dx + dy * dy).sqrt()
+}
+
+fn point_linestring_distance<T>(p: &Point<T>, line: &LineString<T>) -> T
+    where T: Float
+{
+    line.0
+        .windows(2)
+        .map(|seg| point_segment_distance(p, &seg[0], &seg[1]))
+        .fold(T::infinity(), |min, d| if d < min { d } else { min })
+}
+
+/// The signed area of the triangle `a`-`b`-`c`, twice over; its sign tells
+/// which side of the line `a`-`b` the point `c` falls on.
+fn cross<T: Float>(a: &Point<T>, b: &Point<T>, c: &Point<T>) -> T {
+    (b.x() - a.x()) * (c.y() - a.y()) - (b.y() - a.y()) * (c.x() - a.x())
+}
+
+/// Whether `p`, known to be collinear with `a`-`b`, falls within its
+/// bounding box.
+fn on_segment<T: Float>(a: &Point<T>, b: &Point<T>, p: &Point<T>) -> bool {
+    let (xmin, xmax) = if a.x() < b.x() { (a.x(), b.x()) } else { (b.x(), a.x()) };
+    let (ymin, ymax) = if a.y() < b.y() { (a.y(), b.y()) } else { (b.y(), a.y()) };
+    p.x() >= xmin && p.x() <= xmax && p.y() >= ymin && p.y() <= ymax
+}
+
+/// Whether segments `a`-`b` and `c`-`d` share any point.
+fn segments_intersect<T: Float>(a: &Point<T>, b: &Point<T>, c: &Point<T>, d: &Point<T>) -> bool {
+    let d1 = cross(c, d, a);
+    let d2 = cross(c, d, b);
+    let d3 = cross(a, b, c);
+    let d4 = cross(a, b, d);
+
+    if ((d1 > T::zero()) != (d2 > T::zero())) && ((d3 > T::zero()) != (d4 > T::zero())) {
+        return true;
+    }
+    (d1 == T::zero() && on_segment(c, d, a)) ||
+    (d2 == T::zero() && on_segment(c, d, b)) ||
+    (d3 == T::zero() && on_segment(a, b, c)) ||
+    (d4 == T::zero() && on_segment(a, b, d))
+}
+
+fn linestring_linestring_distance<T>(a: &LineString<T>, b: &LineString<T>) -> T
+    where T: Float
+{
+    for seg_a in a.0.windows(2) {
+        for seg_b in b.0.windows(2) {
+            if segments_intersect(&seg_a[0], &seg_a[1], &seg_b[0], &seg_b[1]) {
+                return T::zero();
+            }
+        }
+    }
+    a.0.iter()
+       .map(|p| point_linestring_distance(p, b))
+       .chain(b.0.iter().map(|p| point_linestring_distance(p, a)))
+       .fold(T::infinity(), |min, d| if d < min { d } else { min })
+}
+
+/// Even-odd ray casting point-in-ring test.
+fn point_in_ring<T>(p: &Point<T>, ring: &LineString<T>) -> bool
+    where T: Float
+{
+    let mut inside = false;
+    let points = &ring.0;
+    let mut j = points.len() - 1;
+    for i in 0..points.len() {
+        let (xi, yi) = (points[i].x(), points[i].y());
+        let (xj, yj) = (points[j].x(), points[j].y());
+        if ((yi > p.y()) != (yj > p.y())) &&
+           (p.x() < (xj - xi) * (p.y() - yi) / (yj - yi) + xi) {
+            inside = !inside;
+        }
+        j = i;
+    }
+    inside
+}
+
+fn point_polygon_distance<T>(p: &Point<T>, polygon: &Polygon<T>) -> T
+    where T: Float
+{
+    if point_in_ring(p, &polygon.exterior) &&
+       !polygon.interiors.iter().any(|ring| point_in_ring(p, ring)) {
+        return T::zero();
+    }
+    Some(&polygon.exterior)
+        .into_iter()
+        .chain(polygon.interiors.iter())
+        .map(|ring| point_linestring_distance(p, ring))
+        .fold(T::infinity(), |min, d| if d < min { d } else { min })
+}
+
+impl<T> Distance<T, Point<T>> for Point<T>
+    where T: Float
+{
+    fn distance(&self, rhs: &Point<T>) -> T {
+        point_point_distance(self, rhs)
+    }
+}
+
+impl<T> Distance<T, LineString<T>> for Point<T>
+    where T: Float
+{
+    fn distance(&self, rhs: &LineString<T>) -> T {
+        point_linestring_distance(self, rhs)
+    }
+}
+
+impl<T> Distance<T, Point<T>> for LineString<T>
+    where T: Float
+{
+    fn distance(&self, rhs: &Point<T>) -> T {
+        point_linestring_distance(rhs, self)
+    }
+}
+
+impl<T> Distance<T, Polygon<T>> for Point<T>
+    where T: Float
+{
+    fn distance(&self, rhs: &Polygon<T>) -> T {
+        point_polygon_distance(self, rhs)
+    }
+}
+
+impl<T> Distance<T, Point<T>> for Polygon<T>
+    where T: Float
+{
+    fn distance(&self, rhs: &Point<T>) -> T {
+        point_polygon_distance(rhs, self)
+    }
+}
+
+impl<T> Distance<T, LineString<T>> for LineString<T>
+    where T: Float
+{
+    fn distance(&self, rhs: &LineString<T>) -> T {
+        linestring_linestring_distance(self, rhs)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use types::{Point, LineString, Polygon};
+    use algorithm::distance::Distance;
+
+    #[test]
+    fn point_point_distance_test() {
+        let a = Point::new(0f64, 0.);
+        let b = Point::new(3., 4.);
+        assert_eq!(5., a.distance(&b));
+    }
+    #[test]
+    fn point_linestring_on_segment_test() {
+        let line = LineString(vec![Point::new(0f64, 0.), Point::new(10., 0.)]);
+        let p = Point::new(5., 5.);
+        assert_eq!(5., p.distance(&line));
+    }
+    #[test]
+    fn point_linestring_past_endpoint_test() {
+        let line = LineString(vec![Point::new(0f64, 0.), Point::new(10., 0.)]);
+        let p = Point::new(15., 0.);
+        assert_eq!(5., p.distance(&line));
+    }
+    #[test]
+    fn point_degenerate_segment_test() {
+        let line = LineString(vec![Point::new(1f64, 1.), Point::new(1., 1.)]);
+        let p = Point::new(4., 5.);
+        assert_eq!(5., p.distance(&line));
+    }
+    #[test]
+    fn linestring_linestring_disjoint_test() {
+        let a = LineString(vec![Point::new(0f64, 0.), Point::new(10., 0.)]);
+        let b = LineString(vec![Point::new(0., 5.), Point::new(10., 5.)]);
+        assert_eq!(5., a.distance(&b));
+    }
+    #[test]
+    fn linestring_linestring_crossing_test() {
+        let a = LineString(vec![Point::new(0f64, 0.), Point::new(10., 10.)]);
+        let b = LineString(vec![Point::new(0., 10.), Point::new(10., 0.)]);
+        assert_eq!(0., a.distance(&b));
+    }
+    #[test]
+    fn linestring_linestring_perpendicular_no_intersection_test() {
+        let a = LineString(vec![Point::new(0f64, 0.), Point::new(2., 0.)]);
+        let b = LineString(vec![Point::new(5., 0.), Point::new(5., 2.)]);
+        assert_eq!(3., a.distance(&b));
+    }
+    #[test]
+    fn point_inside_polygon_test() {
+        let polygon = Polygon::new(
+            LineString(vec![Point::new(0f64, 0.), Point::new(0., 10.), Point::new(10., 10.),
+                            Point::new(10., 0.), Point::new(0., 0.)]),
+            Vec::new());
+        let p = Point::new(5., 5.);
+        assert_eq!(0., p.distance(&polygon));
+    }
+    #[test]
+    fn point_outside_polygon_test() {
+        let polygon = Polygon::new(
+            LineString(vec![Point::new(0f64, 0.), Point::new(0., 10.), Point::new(10., 10.),
+                            Point::new(10., 0.), Point::new(0., 0.)]),
+            Vec::new());
+        let p = Point::new(15., 5.);
+        assert_eq!(5., p.distance(&polygon));
+    }
+    #[test]
+    fn point_in_hole_of_polygon_test() {
+        let exterior = LineString(vec![Point::new(0f64, 0.), Point::new(0., 10.),
+                                        Point::new(10., 10.), Point::new(10., 0.),
+                                        Point::new(0., 0.)]);
+        let interior = LineString(vec![Point::new(2., 2.), Point::new(2., 8.),
+                                        Point::new(8., 8.), Point::new(8., 2.),
+                                        Point::new(2., 2.)]);
+        let polygon = Polygon::new(exterior, vec![interior]);
+        let p = Point::new(5., 5.);
+        assert_eq!(3., p.distance(&polygon));
+    }
+}
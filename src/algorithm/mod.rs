@@ -0,0 +1,5 @@
+pub mod boundingbox;
+pub mod cheap_ruler;
+pub mod distance;
+pub mod rtree;
+pub mod clip;
@@ -0,0 +1,109 @@
+extern crate rstar;
+
+use self::rstar::{RTree, RTreeObject, PointDistance, AABB};
+
+use num::Float;
+
+use types::{Bbox, Point, Geometry};
+use algorithm::boundingbox::BoundingBox;
+
+/// A geometry paired with the envelope it was indexed under, so the tree
+/// can be queried without re-deriving bounding boxes on every lookup.
+struct Indexed<'a, T: Float + rstar::RTreeNum + 'a> {
+    geometry: &'a Geometry<T>,
+    envelope: AABB<[T; 2]>,
+}
+
+impl<'a, T> RTreeObject for Indexed<'a, T>
+    where T: Float + rstar::RTreeNum
+{
+    type Envelope = AABB<[T; 2]>;
+
+    fn envelope(&self) -> Self::Envelope {
+        self.envelope
+    }
+}
+
+impl<'a, T> PointDistance for Indexed<'a, T>
+    where T: Float + rstar::RTreeNum
+{
+    fn distance_2(&self, point: &[T; 2]) -> T {
+        self.envelope.distance_2(point)
+    }
+}
+
+fn to_aabb<T: Float + rstar::RTreeNum>(bbox: &Bbox<T>) -> AABB<[T; 2]> {
+    AABB::from_corners([bbox.xmin, bbox.ymin], [bbox.xmax, bbox.ymax])
+}
+
+/// An R-tree index over a set of geometries, keyed on each geometry's
+/// `Bbox`.
+pub struct GeometryIndex<'a, T: Float + rstar::RTreeNum + 'a> {
+    tree: RTree<Indexed<'a, T>>,
+}
+
+impl<'a, T> GeometryIndex<'a, T>
+    where T: Float + rstar::RTreeNum
+{
+    /// Bulk-load an index over `geometries`, skipping any with no bounding
+    /// box (i.e. empty collections).
+    pub fn new(geometries: &'a [Geometry<T>]) -> GeometryIndex<'a, T> {
+        let entries = geometries.iter()
+            .filter_map(|g| g.bbox().map(|bbox| Indexed { geometry: g, envelope: to_aabb(&bbox) }))
+            .collect();
+        GeometryIndex { tree: RTree::bulk_load(entries) }
+    }
+
+    /// All indexed geometries whose envelope intersects `window`.
+    pub fn query_bbox(&self, window: &Bbox<T>) -> Vec<&'a Geometry<T>> {
+        self.tree
+            .locate_in_envelope_intersecting(&to_aabb(window))
+            .map(|entry| entry.geometry)
+            .collect()
+    }
+
+    /// The indexed geometry whose envelope is closest to `point`, or
+    /// `None` if the index is empty.
+    pub fn nearest(&self, point: &Point<T>) -> Option<&'a Geometry<T>> {
+        self.tree
+            .nearest_neighbor(&[point.x(), point.y()])
+            .map(|entry| entry.geometry)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use types::{Point, Geometry};
+    use types::Bbox;
+    use algorithm::rtree::GeometryIndex;
+
+    #[test]
+    fn query_bbox_test() {
+        let geometries = vec![Geometry::Point(Point::new(0f64, 0.)),
+                               Geometry::Point(Point::new(5., 5.)),
+                               Geometry::Point(Point::new(20., 20.))];
+        let index = GeometryIndex::new(&geometries);
+        let window = Bbox{xmin: -1., xmax: 10., ymin: -1., ymax: 10.};
+        let mut found: Vec<_> = index.query_bbox(&window)
+                                      .into_iter()
+                                      .map(|g| match *g {
+                                          Geometry::Point(ref p) => p.x(),
+                                          _ => unreachable!(),
+                                      })
+                                      .collect();
+        found.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        assert_eq!(vec![0., 5.], found);
+    }
+
+    #[test]
+    fn nearest_test() {
+        let geometries = vec![Geometry::Point(Point::new(0f64, 0.)),
+                               Geometry::Point(Point::new(10., 10.))];
+        let index = GeometryIndex::new(&geometries);
+        let nearest = index.nearest(&Point::new(1., 1.)).unwrap();
+        match *nearest {
+            Geometry::Point(ref p) => assert_eq!((0., 0.), (p.x(), p.y())),
+            _ => unreachable!(),
+        }
+    }
+}
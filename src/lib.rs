@@ -0,0 +1,9 @@
+//! This crate provides geospatial primitive types and algorithms.
+
+extern crate num;
+
+pub mod types;
+pub mod algorithm;
+
+pub use types::{Coordinate, Point, LineString, Polygon, MultiPoint, MultiLineString,
+                MultiPolygon, Geometry, GeometryCollection, Bbox};
@@ -0,0 +1,204 @@
+use num::Float;
+
+/// A single (x, y) coordinate pair.
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub struct Coordinate<T: Float> {
+    pub x: T,
+    pub y: T,
+}
+
+impl<T: Float> Coordinate<T> {
+    pub fn new(x: T, y: T) -> Coordinate<T> {
+        Coordinate { x: x, y: y }
+    }
+}
+
+/// A single point in space.
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub struct Point<T: Float>(pub Coordinate<T>);
+
+impl<T: Float> Point<T> {
+    pub fn new(x: T, y: T) -> Point<T> {
+        Point(Coordinate::new(x, y))
+    }
+
+    pub fn x(&self) -> T {
+        self.0.x
+    }
+
+    pub fn y(&self) -> T {
+        self.0.y
+    }
+}
+
+/// An ordered collection of two or more points, representing a path.
+#[derive(PartialEq, Clone, Debug)]
+pub struct LineString<T: Float>(pub Vec<Point<T>>);
+
+/// A collection of points that share no implied connectivity.
+#[derive(PartialEq, Clone, Debug)]
+pub struct MultiPoint<T: Float>(pub Vec<Point<T>>);
+
+/// A collection of `LineString`s.
+#[derive(PartialEq, Clone, Debug)]
+pub struct MultiLineString<T: Float>(pub Vec<LineString<T>>);
+
+/// A bounded area made up of one exterior ring and zero or more interior
+/// rings (holes).
+#[derive(PartialEq, Clone, Debug)]
+pub struct Polygon<T: Float> {
+    pub exterior: LineString<T>,
+    pub interiors: Vec<LineString<T>>,
+}
+
+impl<T: Float> Polygon<T> {
+    pub fn new(exterior: LineString<T>, interiors: Vec<LineString<T>>) -> Polygon<T> {
+        Polygon { exterior: exterior, interiors: interiors }
+    }
+}
+
+/// A collection of `Polygon`s.
+#[derive(PartialEq, Clone, Debug)]
+pub struct MultiPolygon<T: Float>(pub Vec<Polygon<T>>);
+
+/// A heterogeneous collection of any of the other geometry variants.
+#[derive(PartialEq, Clone, Debug)]
+pub struct GeometryCollection<T: Float>(pub Vec<Geometry<T>>);
+
+/// An enum wrapping any of the concrete geometry types.
+#[derive(PartialEq, Clone, Debug)]
+pub enum Geometry<T: Float> {
+    Point(Point<T>),
+    LineString(LineString<T>),
+    Polygon(Polygon<T>),
+    MultiPoint(MultiPoint<T>),
+    MultiLineString(MultiLineString<T>),
+    MultiPolygon(MultiPolygon<T>),
+    GeometryCollection(GeometryCollection<T>),
+}
+
+/// An axis-aligned bounding box.
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub struct Bbox<T: Float> {
+    pub xmin: T,
+    pub xmax: T,
+    pub ymin: T,
+    pub ymax: T,
+}
+
+impl<T: Float> Bbox<T> {
+    /// The smallest `Bbox` that covers both `self` and `other`.
+    pub fn union(&self, other: &Bbox<T>) -> Bbox<T> {
+        Bbox {
+            xmin: if self.xmin < other.xmin { self.xmin } else { other.xmin },
+            xmax: if self.xmax > other.xmax { self.xmax } else { other.xmax },
+            ymin: if self.ymin < other.ymin { self.ymin } else { other.ymin },
+            ymax: if self.ymax > other.ymax { self.ymax } else { other.ymax },
+        }
+    }
+
+    /// The overlapping area of `self` and `other`, or `None` if they don't
+    /// overlap.
+    pub fn intersection(&self, other: &Bbox<T>) -> Option<Bbox<T>> {
+        let xmin = if self.xmin > other.xmin { self.xmin } else { other.xmin };
+        let xmax = if self.xmax < other.xmax { self.xmax } else { other.xmax };
+        let ymin = if self.ymin > other.ymin { self.ymin } else { other.ymin };
+        let ymax = if self.ymax < other.ymax { self.ymax } else { other.ymax };
+        if xmin > xmax || ymin > ymax {
+            None
+        } else {
+            Some(Bbox { xmin: xmin, xmax: xmax, ymin: ymin, ymax: ymax })
+        }
+    }
+
+    /// Whether `other` lies entirely within `self`.
+    pub fn contains(&self, other: &Bbox<T>) -> bool {
+        self.xmin <= other.xmin && self.xmax >= other.xmax &&
+        self.ymin <= other.ymin && self.ymax >= other.ymax
+    }
+
+    /// Whether `point` lies within `self`, inclusive of the boundary.
+    pub fn contains_point(&self, point: &Point<T>) -> bool {
+        point.x() >= self.xmin && point.x() <= self.xmax &&
+        point.y() >= self.ymin && point.y() <= self.ymax
+    }
+
+    /// Dilate (or, with a negative `dx`/`dy`, shrink) `self` by `dx` on
+    /// either side of x and `dy` on either side of y.
+    pub fn expand(&self, dx: T, dy: T) -> Bbox<T> {
+        Bbox {
+            xmin: self.xmin - dx,
+            xmax: self.xmax + dx,
+            ymin: self.ymin - dy,
+            ymax: self.ymax + dy,
+        }
+    }
+
+    /// A copy of `self` grown just enough to cover `point`; `self` itself is
+    /// left unchanged.
+    pub fn add_point(&self, point: &Point<T>) -> Bbox<T> {
+        Bbox {
+            xmin: if point.x() < self.xmin { point.x() } else { self.xmin },
+            xmax: if point.x() > self.xmax { point.x() } else { self.xmax },
+            ymin: if point.y() < self.ymin { point.y() } else { self.ymin },
+            ymax: if point.y() > self.ymax { point.y() } else { self.ymax },
+        }
+    }
+
+    /// A copy of `self` grown just enough to also cover `other`; `self`
+    /// itself is left unchanged. An alias for `union`.
+    pub fn merge(&self, other: &Bbox<T>) -> Bbox<T> {
+        self.union(other)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use types::{Bbox, Point};
+
+    #[test]
+    fn union_test() {
+        let a = Bbox{xmin: 0., xmax: 2., ymin: 0., ymax: 2.};
+        let b = Bbox{xmin: 1., xmax: 3., ymin: -1., ymax: 1.};
+        let union = Bbox{xmin: 0., xmax: 3., ymin: -1., ymax: 2.};
+        assert_eq!(union, a.union(&b));
+    }
+    #[test]
+    fn intersection_test() {
+        let a = Bbox{xmin: 0., xmax: 2., ymin: 0., ymax: 2.};
+        let b = Bbox{xmin: 1., xmax: 3., ymin: -1., ymax: 1.};
+        let intersection = Bbox{xmin: 1., xmax: 2., ymin: 0., ymax: 1.};
+        assert_eq!(Some(intersection), a.intersection(&b));
+    }
+    #[test]
+    fn disjoint_intersection_test() {
+        let a = Bbox{xmin: 0., xmax: 1., ymin: 0., ymax: 1.};
+        let b = Bbox{xmin: 2., xmax: 3., ymin: 2., ymax: 3.};
+        assert!(a.intersection(&b).is_none());
+    }
+    #[test]
+    fn contains_test() {
+        let outer = Bbox{xmin: 0., xmax: 10., ymin: 0., ymax: 10.};
+        let inner = Bbox{xmin: 2., xmax: 4., ymin: 2., ymax: 4.};
+        assert!(outer.contains(&inner));
+        assert!(!inner.contains(&outer));
+    }
+    #[test]
+    fn contains_point_test() {
+        let bbox = Bbox{xmin: 0., xmax: 10., ymin: 0., ymax: 10.};
+        assert!(bbox.contains_point(&Point::new(5., 5.)));
+        assert!(!bbox.contains_point(&Point::new(11., 5.)));
+    }
+    #[test]
+    fn expand_test() {
+        let bbox = Bbox{xmin: 0., xmax: 10., ymin: 0., ymax: 10.};
+        let expanded = Bbox{xmin: -1., xmax: 11., ymin: -1., ymax: 11.};
+        assert_eq!(expanded, bbox.expand(1., 1.));
+    }
+    #[test]
+    fn add_point_test() {
+        let bbox = Bbox{xmin: 0., xmax: 10., ymin: 0., ymax: 10.};
+        let grown = Bbox{xmin: 0., xmax: 12., ymin: -2., ymax: 10.};
+        assert_eq!(grown, bbox.add_point(&Point::new(12., -2.)));
+    }
+}